@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines aggregate kernels that reduce a single `PrimitiveArray` to a scalar,
+//! skipping nulls rather than treating them as zero.
+
+use num::{ToPrimitive, Zero};
+
+use crate::array::PrimitiveArray;
+use crate::datatypes::ArrowNumericType;
+
+/// Returns the sum of the non-null values in the array, or `None` if the array is
+/// empty or all-null.
+pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: Zero + std::ops::Add<Output = T::Native>,
+{
+    let mut found_value = false;
+    let mut result = T::Native::zero();
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            continue;
+        }
+        found_value = true;
+        result = result + array.value(i);
+    }
+    if found_value {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Returns the minimum value in the array, or `None` if the array is empty or
+/// all-null.
+pub fn min<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: PartialOrd,
+{
+    min_max_helper(array, |a, b| a < b)
+}
+
+/// Returns the maximum value in the array, or `None` if the array is empty or
+/// all-null.
+pub fn max<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: PartialOrd,
+{
+    min_max_helper(array, |a, b| a > b)
+}
+
+/// Helper function to calculate min/max of the array. `cmp` is a predicate that
+/// returns `true` if `a` should replace `b` as the running result.
+fn min_max_helper<T, F>(array: &PrimitiveArray<T>, cmp: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    let mut result = None;
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            continue;
+        }
+        let value = array.value(i);
+        result = match result {
+            None => Some(value),
+            Some(current) if cmp(value, current) => Some(value),
+            Some(current) => Some(current),
+        };
+    }
+    result
+}
+
+/// Returns the arithmetic mean of the non-null values in the array as `f64`, or
+/// `None` if the array is empty or all-null.
+pub fn mean<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    let mut sum = 0_f64;
+    let mut count = 0_usize;
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            continue;
+        }
+        sum += array.value(i).to_f64().unwrap();
+        count += 1;
+    }
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn test_sum() {
+        let a = Int32Array::from(vec![1, 2, 3, 4, 5]);
+        assert_eq!(15, sum(&a).unwrap());
+    }
+
+    #[test]
+    fn test_sum_with_nulls() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None, Some(5)]);
+        assert_eq!(9, sum(&a).unwrap());
+    }
+
+    #[test]
+    fn test_sum_all_nulls() {
+        let a = Int32Array::from(vec![None, None, None]);
+        assert_eq!(None, sum(&a));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let a = Int32Array::from(vec![Some(5), None, Some(1), Some(9), None]);
+        assert_eq!(1, min(&a).unwrap());
+        assert_eq!(9, max(&a).unwrap());
+    }
+
+    #[test]
+    fn test_mean() {
+        let a = Int32Array::from(vec![1, 2, 3, 4]);
+        assert_eq!(2.5, mean(&a).unwrap());
+    }
+
+    #[test]
+    fn test_mean_empty() {
+        let a = Int32Array::from(Vec::<i32>::new());
+        assert_eq!(None, mean(&a));
+    }
+}
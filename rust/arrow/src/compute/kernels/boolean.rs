@@ -0,0 +1,153 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines Kleene (SQL) three-valued logic kernels for `BooleanArray`s, where a null
+//! represents an unknown value rather than `false`: `true OR null = true`,
+//! `false OR null = null`, `false AND null = false`, `true AND null = null`, and
+//! `null AND null = null`.
+
+use crate::array::BooleanArray;
+use crate::error::{ArrowError, Result};
+
+/// Performs `AND` on two `BooleanArray`s using Kleene three-valued logic.
+pub fn and(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_op(left, right, |l, r| match (l, r) {
+        (Some(false), _) | (_, Some(false)) => (false, true),
+        (Some(true), Some(true)) => (true, true),
+        _ => (false, false),
+    })
+}
+
+/// Performs `OR` on two `BooleanArray`s using Kleene three-valued logic.
+pub fn or(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_op(left, right, |l, r| match (l, r) {
+        (Some(true), _) | (_, Some(true)) => (true, true),
+        (Some(false), Some(false)) => (false, true),
+        _ => (false, false),
+    })
+}
+
+/// Performs `NOT` on a `BooleanArray`, flipping the value where valid and leaving the
+/// validity bitmap untouched (`not null = null`).
+pub fn not(array: &BooleanArray) -> Result<BooleanArray> {
+    let mut b = BooleanArray::builder(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+        } else {
+            b.append_value(!array.value(i))?;
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Helper that applies `op` to the values of both inputs at index `i`, each passed as
+/// an `Option<bool>` that is `None` for a null slot, where `op` returns
+/// `(result_value, result_is_valid)`.
+fn binary_op<F>(left: &BooleanArray, right: &BooleanArray, op: F) -> Result<BooleanArray>
+where
+    F: Fn(Option<bool>, Option<bool>) -> (bool, bool),
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform boolean operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let mut b = BooleanArray::builder(left.len());
+    for i in 0..left.len() {
+        let l = if left.is_null(i) {
+            None
+        } else {
+            Some(left.value(i))
+        };
+        let r = if right.is_null(i) {
+            None
+        } else {
+            Some(right.value(i))
+        };
+        let (value, is_valid) = op(l, r);
+        if is_valid {
+            b.append_value(value)?;
+        } else {
+            b.append_null()?;
+        }
+    }
+    Ok(b.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and() {
+        let a = BooleanArray::from(vec![false, false, true, true]);
+        let b = BooleanArray::from(vec![false, true, false, true]);
+        let c = and(&a, &b).unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(false, c.value(2));
+        assert_eq!(true, c.value(3));
+    }
+
+    #[test]
+    fn test_or() {
+        let a = BooleanArray::from(vec![false, false, true, true]);
+        let b = BooleanArray::from(vec![false, true, false, true]);
+        let c = or(&a, &b).unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(true, c.value(1));
+        assert_eq!(true, c.value(2));
+        assert_eq!(true, c.value(3));
+    }
+
+    #[test]
+    fn test_and_kleene_nulls() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None, None]);
+        let b = BooleanArray::from(vec![None, None, Some(true), Some(false)]);
+        let c = and(&a, &b).unwrap();
+        assert_eq!(true, c.is_null(0));
+        assert_eq!(false, c.is_null(1));
+        assert_eq!(false, c.value(1));
+        assert_eq!(true, c.is_null(2));
+        assert_eq!(false, c.is_null(3));
+        assert_eq!(false, c.value(3));
+    }
+
+    #[test]
+    fn test_or_kleene_nulls() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None, None]);
+        let b = BooleanArray::from(vec![None, None, Some(false), Some(true)]);
+        let c = or(&a, &b).unwrap();
+        assert_eq!(false, c.is_null(0));
+        assert_eq!(true, c.value(0));
+        assert_eq!(true, c.is_null(1));
+        assert_eq!(true, c.is_null(2));
+        assert_eq!(false, c.is_null(3));
+        assert_eq!(true, c.value(3));
+    }
+
+    #[test]
+    fn test_not() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let c = not(&a).unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(true, c.value(1));
+        assert_eq!(true, c.is_null(2));
+    }
+}
@@ -0,0 +1,120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines comparison kernels for `PrimitiveArray`s.
+//!
+//! These kernels follow SQL's three-valued logic: a null in either operand makes the
+//! result null, rather than `false`.
+
+use crate::array::{BooleanArray, PrimitiveArray};
+use crate::datatypes::ArrowNumericType;
+use crate::error::{ArrowError, Result};
+
+/// Helper function to perform a comparison lambda function on values from two arrays,
+/// propagating nulls per SQL semantics: a null slot in either input yields a null
+/// output slot.
+fn compare_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let mut b = BooleanArray::builder(left.len());
+    for i in 0..left.len() {
+        if left.is_null(i) || right.is_null(i) {
+            b.append_null()?;
+        } else {
+            b.append_value(op(left.value(i), right.value(i)))?;
+        }
+    }
+    Ok(b.finish())
+}
+
+macro_rules! compare_op {
+    ($NAME:ident, $OP:tt) => {
+        pub fn $NAME<T>(left: &PrimitiveArray<T>, right: &PrimitiveArray<T>) -> Result<BooleanArray>
+        where
+            T: ArrowNumericType,
+            T::Native: PartialOrd,
+        {
+            compare_op(left, right, |a, b| a $OP b)
+        }
+    };
+}
+
+compare_op!(eq, ==);
+compare_op!(neq, !=);
+compare_op!(lt, <);
+compare_op!(lt_eq, <=);
+compare_op!(gt, >);
+compare_op!(gt_eq, >=);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn test_eq() {
+        let a = Int32Array::from(vec![8, 8, 8, 8, 8]);
+        let b = Int32Array::from(vec![6, 7, 8, 9, 10]);
+        let c = eq(&a, &b).unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(true, c.value(2));
+        assert_eq!(false, c.value(3));
+        assert_eq!(false, c.value(4));
+    }
+
+    #[test]
+    fn test_lt() {
+        let a = Int32Array::from(vec![8, 8, 8, 8, 8]);
+        let b = Int32Array::from(vec![6, 7, 8, 9, 10]);
+        let c = lt(&a, &b).unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(false, c.value(2));
+        assert_eq!(true, c.value(3));
+        assert_eq!(true, c.value(4));
+    }
+
+    #[test]
+    fn test_null_propagation() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![Some(1), Some(2), None]);
+        let c = eq(&a, &b).unwrap();
+        assert_eq!(false, c.is_null(0));
+        assert_eq!(true, c.is_null(1));
+        assert_eq!(true, c.is_null(2));
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int32Array::from(vec![1, 2, 3]);
+        assert!(eq(&a, &b).is_err());
+    }
+}
@@ -17,101 +17,63 @@
 
 //! Defines miscellaneous array kernels.
 
-use std::sync::Arc;
-
 use crate::array::*;
-use crate::datatypes::{ArrowNumericType, DataType};
-use crate::error::{ArrowError, Result};
+use crate::compute::kernels::take::take;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
 
-/// Helper function to perform boolean lambda function on values from two arrays.
-fn bool_op<T, F>(
-    left: &PrimitiveArray<T>,
-    right: &PrimitiveArray<T>,
-    op: F,
-) -> Result<BooleanArray>
-where
-    T: ArrowNumericType,
-    F: Fn(Option<T::Native>, Option<T::Native>) -> bool,
-{
-    if left.len() != right.len() {
-        return Err(ArrowError::ComputeError(
-            "Cannot perform math operation on arrays of different length".to_string(),
-        ));
-    }
-    let mut b = BooleanArray::builder(left.len());
-    for i in 0..left.len() {
-        let index = i;
-        let l = if left.is_null(i) {
-            None
-        } else {
-            Some(left.value(index))
-        };
-        let r = if right.is_null(i) {
-            None
-        } else {
-            Some(right.value(index))
-        };
-        b.append_value(op(l, r))?;
-    }
-    Ok(b.finish())
+/// A reusable selection computed once from a `BooleanArray` mask and applied to as
+/// many columns as needed. Building the selection vector is the expensive part of
+/// filtering (a full scan of the mask); `Filter` does that once and then gathers each
+/// column with [`take`](super::take::take) over the precomputed indices instead of
+/// re-scanning the mask per column.
+pub struct Filter {
+    /// Row indices selected by the mask, in order. `indices.len()` is the popcount of
+    /// the mask and therefore the length of every filtered column.
+    indices: UInt32Array,
 }
 
-macro_rules! filter_array {
-    ($array:expr, $filter:expr, $array_type:ident) => {{
-        let b = $array.as_any().downcast_ref::<$array_type>().unwrap();
-        let mut builder = $array_type::builder(b.len());
-        for i in 0..b.len() {
-            if $filter.value(i) {
-                if b.is_null(i) {
-                    builder.append_null()?;
-                } else {
-                    builder.append_value(b.value(i))?;
-                }
+impl Filter {
+    /// Precomputes the selection vector for `filter`.
+    pub fn new(filter: &BooleanArray) -> Self {
+        let mut selected = Vec::with_capacity(filter.len());
+        for i in 0..filter.len() {
+            if filter.value(i) {
+                selected.push(i as u32);
             }
         }
-        Ok(Arc::new(builder.finish()))
-    }};
+        Self {
+            indices: UInt32Array::from(selected),
+        }
+    }
+
+    /// The number of rows this filter selects.
+    pub fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `array`, keeping only the rows selected by this filter's mask.
+    pub fn filter(&self, array: &Array) -> Result<ArrayRef> {
+        take(array, &self.indices)
+    }
+
+    /// Applies this filter to every column of `batch`, returning a `RecordBatch` with
+    /// the same schema and only the selected rows.
+    pub fn filter_record_batch(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| self.filter(column.as_ref()))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        RecordBatch::try_new(batch.schema().clone(), columns)
+    }
 }
 
-/// Returns the array, taking only the elements matching the filter
+/// Returns the array, taking only the elements matching the filter. This is a thin,
+/// one-shot wrapper over [`Filter`] kept for backward compatibility; prefer building a
+/// `Filter` directly when filtering more than one array by the same mask.
 pub fn filter(array: &Array, filter: &BooleanArray) -> Result<ArrayRef> {
-    match array.data_type() {
-        DataType::UInt8 => filter_array!(array, filter, UInt8Array),
-        DataType::UInt16 => filter_array!(array, filter, UInt16Array),
-        DataType::UInt32 => filter_array!(array, filter, UInt32Array),
-        DataType::UInt64 => filter_array!(array, filter, UInt64Array),
-        DataType::Int8 => filter_array!(array, filter, Int8Array),
-        DataType::Int16 => filter_array!(array, filter, Int16Array),
-        DataType::Int32 => filter_array!(array, filter, Int32Array),
-        DataType::Int64 => filter_array!(array, filter, Int64Array),
-        DataType::Float32 => filter_array!(array, filter, Float32Array),
-        DataType::Float64 => filter_array!(array, filter, Float64Array),
-        DataType::Boolean => filter_array!(array, filter, BooleanArray),
-        DataType::Binary => {
-            let b = array.as_any().downcast_ref::<BinaryArray>().unwrap();
-            let mut values: Vec<&[u8]> = Vec::with_capacity(b.len());
-            for i in 0..b.len() {
-                if filter.value(i) {
-                    values.push(b.value(i));
-                }
-            }
-            Ok(Arc::new(BinaryArray::from(values)))
-        }
-        DataType::Utf8 => {
-            let b = array.as_any().downcast_ref::<StringArray>().unwrap();
-            let mut values: Vec<&str> = Vec::with_capacity(b.len());
-            for i in 0..b.len() {
-                if filter.value(i) {
-                    values.push(b.value(i));
-                }
-            }
-            Ok(Arc::new(StringArray::from(values)))
-        }
-        other => Err(ArrowError::ComputeError(format!(
-            "filter not supported for {:?}",
-            other
-        ))),
-    }
+    Filter::new(filter).filter(array)
 }
 
 #[cfg(test)]
@@ -149,4 +111,33 @@ mod tests {
         assert_eq!(1, d.len());
         assert_eq!(true, d.is_null(0));
     }
+
+    #[test]
+    fn test_filter_string_array_preserves_null() {
+        // Regression test: the old per-type filter branches for Binary/Utf8 pushed
+        // `value(i)` unconditionally, so a selected null became an empty string
+        // instead of staying null.
+        let a = StringArray::from(vec![Some("hello"), None, Some("world")]);
+        let b = BooleanArray::from(vec![true, true, true]);
+        let c = filter(&a, &b).unwrap();
+        let d = c.as_ref().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(3, d.len());
+        assert_eq!(false, d.is_null(0));
+        assert_eq!(true, d.is_null(1));
+        assert_eq!(false, d.is_null(2));
+    }
+
+    #[test]
+    fn test_filter_reused_across_columns() {
+        let mask = BooleanArray::from(vec![true, false, true, true]);
+        let f = Filter::new(&mask);
+        assert_eq!(3, f.count());
+
+        let a = Int32Array::from(vec![1, 2, 3, 4]);
+        let b = StringArray::from(vec!["a", "b", "c", "d"]);
+        let fa = f.filter(&a).unwrap();
+        let fb = f.filter(&b).unwrap();
+        assert_eq!(3, fa.len());
+        assert_eq!(3, fb.len());
+    }
 }
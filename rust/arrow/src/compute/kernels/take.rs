@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the `take` kernel, which gathers rows of an array by index. `filter` is the
+//! special case of `take` over the indices where a boolean mask is true, and is
+//! implemented that way.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+macro_rules! take_array {
+    ($array:expr, $indices:expr, $array_type:ident) => {{
+        let a = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        let mut builder = $array_type::builder($indices.len());
+        for i in 0..$indices.len() {
+            if $indices.is_null(i) {
+                builder.append_null()?;
+                continue;
+            }
+            let index = $indices.value(i) as usize;
+            if index >= a.len() {
+                return Err(ArrowError::ComputeError(format!(
+                    "Index out of bounds: {} is not valid for an array of length {}",
+                    index,
+                    a.len()
+                )));
+            }
+            if a.is_null(index) {
+                builder.append_null()?;
+            } else {
+                builder.append_value(a.value(index))?;
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }};
+}
+
+/// Takes elements from `array` by `indices`, producing a new array whose element `j`
+/// is `array[indices[j]]`. A null slot in `indices` yields a null output slot; an
+/// out-of-bounds index is a `ComputeError`.
+pub fn take(array: &Array, indices: &UInt32Array) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::UInt8 => take_array!(array, indices, UInt8Array),
+        DataType::UInt16 => take_array!(array, indices, UInt16Array),
+        DataType::UInt32 => take_array!(array, indices, UInt32Array),
+        DataType::UInt64 => take_array!(array, indices, UInt64Array),
+        DataType::Int8 => take_array!(array, indices, Int8Array),
+        DataType::Int16 => take_array!(array, indices, Int16Array),
+        DataType::Int32 => take_array!(array, indices, Int32Array),
+        DataType::Int64 => take_array!(array, indices, Int64Array),
+        DataType::Float32 => take_array!(array, indices, Float32Array),
+        DataType::Float64 => take_array!(array, indices, Float64Array),
+        DataType::Boolean => take_array!(array, indices, BooleanArray),
+        DataType::Binary => {
+            let b = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            let mut builder = BinaryBuilder::new(indices.len());
+            for i in 0..indices.len() {
+                if indices.is_null(i) {
+                    builder.append_null()?;
+                    continue;
+                }
+                let index = indices.value(i) as usize;
+                if index >= b.len() {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Index out of bounds: {} is not valid for an array of length {}",
+                        index,
+                        b.len()
+                    )));
+                }
+                if b.is_null(index) {
+                    builder.append_null()?;
+                } else {
+                    builder.append_value(b.value(index))?;
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        DataType::Utf8 => {
+            let s = array.as_any().downcast_ref::<StringArray>().unwrap();
+            let mut builder = StringBuilder::new(indices.len());
+            for i in 0..indices.len() {
+                if indices.is_null(i) {
+                    builder.append_null()?;
+                    continue;
+                }
+                let index = indices.value(i) as usize;
+                if index >= s.len() {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Index out of bounds: {} is not valid for an array of length {}",
+                        index,
+                        s.len()
+                    )));
+                }
+                if s.is_null(index) {
+                    builder.append_null()?;
+                } else {
+                    builder.append_value(s.value(index))?;
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "take not supported for {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_primitive() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        let indices = UInt32Array::from(vec![3, 0, 3, 1]);
+        let c = take(&a, &indices).unwrap();
+        let d = c.as_ref().as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(4, d.len());
+        assert_eq!(8, d.value(0));
+        assert_eq!(5, d.value(1));
+        assert_eq!(8, d.value(2));
+        assert_eq!(6, d.value(3));
+    }
+
+    #[test]
+    fn test_take_primitive_with_null_index() {
+        let a = Int32Array::from(vec![5, 6, 7]);
+        let indices = UInt32Array::from(vec![Some(2), None, Some(0)]);
+        let c = take(&a, &indices).unwrap();
+        let d = c.as_ref().as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(7, d.value(0));
+        assert_eq!(true, d.is_null(1));
+        assert_eq!(5, d.value(2));
+    }
+
+    #[test]
+    fn test_take_string_array() {
+        let a = StringArray::from(vec!["hello", " ", "world", "!"]);
+        let indices = UInt32Array::from(vec![2, 0]);
+        let c = take(&a, &indices).unwrap();
+        let d = c.as_ref().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("world", d.value(0));
+        assert_eq!("hello", d.value(1));
+    }
+
+    #[test]
+    fn test_take_out_of_bounds() {
+        let a = Int32Array::from(vec![5, 6, 7]);
+        let indices = UInt32Array::from(vec![5]);
+        assert!(take(&a, &indices).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the error type used throughout this crate.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The result type returned by fallible operations in this crate.
+pub type Result<T> = std::result::Result<T, ArrowError>;
+
+#[derive(Debug)]
+pub enum ArrowError {
+    /// A compute kernel could not be applied to its inputs, e.g. mismatched array
+    /// lengths or an unsupported data type.
+    ComputeError(String),
+    /// An arithmetic kernel was asked to divide by a non-null zero divisor.
+    DivideByZero,
+}
+
+impl Display for ArrowError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ArrowError::ComputeError(desc) => write!(f, "Compute error: {}", desc),
+            ArrowError::DivideByZero => write!(f, "Divide by zero error"),
+        }
+    }
+}
+
+impl Error for ArrowError {}